@@ -0,0 +1,540 @@
+//! [`num_traits::Float`] (and the traits it requires) for [`Dual`], so a
+//! `Dual<F, N>` can be dropped into any generic numeric code written
+//! against `F: Float` — Newton iteration, root finders, anything from the
+//! `num` ecosystem — and come out the other side carrying derivatives.
+
+use std::num::FpCategory;
+
+use num_traits::{Float, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+use crate::Dual;
+
+impl<F: Float, const N: usize> Zero for Dual<F, N> {
+    fn zero() -> Self {
+        Dual::constant(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero()
+    }
+}
+
+impl<F: Float, const N: usize> One for Dual<F, N> {
+    fn one() -> Self {
+        Dual::constant(F::one())
+    }
+}
+
+impl<F: Float, const N: usize> std::ops::Rem for Dual<F, N> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Dual {
+            x: self.x % rhs.x,
+            dx: self.dx,
+        }
+    }
+}
+
+impl<F: Float, const N: usize> ToPrimitive for Dual<F, N> {
+    fn to_i64(&self) -> Option<i64> {
+        self.x.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.x.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.x.to_f64()
+    }
+}
+
+impl<F: Float, const N: usize> NumCast for Dual<F, N> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        F::from(n).map(Dual::constant)
+    }
+}
+
+impl<F: Float, const N: usize> Num for Dual<F, N> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        F::from_str_radix(str, radix).map(Dual::constant)
+    }
+}
+
+impl<F: Float, const N: usize> Signed for Dual<F, N> {
+    fn abs(&self) -> Self {
+        Dual::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.x <= other.x {
+            Dual::constant(F::zero())
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Dual::constant(self.x.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.x.is_sign_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.x.is_sign_negative()
+    }
+}
+
+impl<F: Float, const N: usize> Float for Dual<F, N> {
+    fn nan() -> Self {
+        Dual::constant(F::nan())
+    }
+
+    fn infinity() -> Self {
+        Dual::constant(F::infinity())
+    }
+
+    fn neg_infinity() -> Self {
+        Dual::constant(F::neg_infinity())
+    }
+
+    fn neg_zero() -> Self {
+        Dual::constant(F::neg_zero())
+    }
+
+    fn min_value() -> Self {
+        Dual::constant(F::min_value())
+    }
+
+    fn min_positive_value() -> Self {
+        Dual::constant(F::min_positive_value())
+    }
+
+    fn max_value() -> Self {
+        Dual::constant(F::max_value())
+    }
+
+    fn is_nan(self) -> bool {
+        self.x.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.x.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.x.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.x.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.x.classify()
+    }
+
+    fn floor(self) -> Self {
+        Dual {
+            x: self.x.floor(),
+            dx: [F::zero(); N],
+        }
+    }
+
+    fn ceil(self) -> Self {
+        Dual {
+            x: self.x.ceil(),
+            dx: [F::zero(); N],
+        }
+    }
+
+    fn round(self) -> Self {
+        Dual {
+            x: self.x.round(),
+            dx: [F::zero(); N],
+        }
+    }
+
+    fn trunc(self) -> Self {
+        Dual {
+            x: self.x.trunc(),
+            dx: [F::zero(); N],
+        }
+    }
+
+    fn fract(self) -> Self {
+        Dual {
+            x: self.x.fract(),
+            dx: self.dx,
+        }
+    }
+
+    fn abs(self) -> Self {
+        Dual::abs(self)
+    }
+
+    fn signum(self) -> Self {
+        Dual::constant(self.x.signum())
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.x.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.x.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Dual::mul_add(self, a, b)
+    }
+
+    fn recip(self) -> Self {
+        Dual {
+            x: self.x.recip(),
+            dx: self.dx.map(|d| -d / (self.x * self.x)),
+        }
+    }
+
+    fn powi(self, n: i32) -> Self {
+        Dual::powi(self, n)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let x = self.x.powf(n.x);
+        let d_base = n.x * self.x.powf(n.x - F::one());
+        let d_exp = x * self.x.ln();
+        Dual {
+            x,
+            dx: std::array::from_fn(|i| d_base * self.dx[i] + d_exp * n.dx[i]),
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        Dual::sqrt(self)
+    }
+
+    fn exp(self) -> Self {
+        Dual::exp(self)
+    }
+
+    fn exp2(self) -> Self {
+        Dual::exp2(self)
+    }
+
+    fn ln(self) -> Self {
+        Dual::ln(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        Dual::log(self, base.value())
+    }
+
+    fn log2(self) -> Self {
+        Dual::log2(self)
+    }
+
+    fn log10(self) -> Self {
+        Dual::log10(self)
+    }
+
+    fn to_degrees(self) -> Self {
+        let factor = F::from(180.0).unwrap() / F::from(std::f64::consts::PI).unwrap();
+        Dual {
+            x: self.x.to_degrees(),
+            dx: self.dx.map(|d| d * factor),
+        }
+    }
+
+    fn to_radians(self) -> Self {
+        let factor = F::from(std::f64::consts::PI).unwrap() / F::from(180.0).unwrap();
+        Dual {
+            x: self.x.to_radians(),
+            dx: self.dx.map(|d| d * factor),
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self.x >= other.x {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self.x <= other.x {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.x <= other.x {
+            Dual::constant(F::zero())
+        } else {
+            self - other
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        Dual::cbrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        Dual::hypot(self, other)
+    }
+
+    fn sin(self) -> Self {
+        Dual::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Dual::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        Dual::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        Dual::asin(self)
+    }
+
+    fn acos(self) -> Self {
+        Dual::acos(self)
+    }
+
+    fn atan(self) -> Self {
+        Dual::atan(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Dual::atan2(self, other)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        let exp = self.x.exp();
+        Dual {
+            x: self.x.exp_m1(),
+            dx: self.dx.map(|d| exp * d),
+        }
+    }
+
+    fn ln_1p(self) -> Self {
+        Dual {
+            x: self.x.ln_1p(),
+            dx: self.dx.map(|d| d / (F::one() + self.x)),
+        }
+    }
+
+    fn sinh(self) -> Self {
+        Dual {
+            x: self.x.sinh(),
+            dx: self.dx.map(|d| self.x.cosh() * d),
+        }
+    }
+
+    fn cosh(self) -> Self {
+        Dual {
+            x: self.x.cosh(),
+            dx: self.dx.map(|d| self.x.sinh() * d),
+        }
+    }
+
+    fn tanh(self) -> Self {
+        let tanh = self.x.tanh();
+        Dual {
+            x: tanh,
+            dx: self.dx.map(|d| (F::one() - tanh * tanh) * d),
+        }
+    }
+
+    fn asinh(self) -> Self {
+        let denom = (self.x * self.x + F::one()).sqrt();
+        Dual {
+            x: self.x.asinh(),
+            dx: self.dx.map(|d| d / denom),
+        }
+    }
+
+    fn acosh(self) -> Self {
+        let denom = (self.x * self.x - F::one()).sqrt();
+        Dual {
+            x: self.x.acosh(),
+            dx: self.dx.map(|d| d / denom),
+        }
+    }
+
+    fn atanh(self) -> Self {
+        let denom = F::one() - self.x * self.x;
+        Dual {
+            x: self.x.atanh(),
+            dx: self.dx.map(|d| d / denom),
+        }
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.x.integer_decode()
+    }
+
+    fn epsilon() -> Self {
+        Dual::constant(F::epsilon())
+    }
+}
+
+/// Evaluates `f` at `x0` and returns its derivative, seeding a single
+/// [`Dual`] variable so callers don't have to construct one by hand:
+/// `differentiate(4.0, |x| x.sqrt() + 1.0)` is `0.25`.
+pub fn differentiate<F: Float>(x0: F, f: impl Fn(Dual<F, 1>) -> Dual<F, 1>) -> F {
+    f(Dual::variable(x0, 0)).derivative()[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn differentiate_sqrt_plus_const() {
+        let d = differentiate(4.0, |x| x.sqrt() + 1.0);
+        assert_eq!(d, 0.25);
+    }
+
+    #[test]
+    fn powf() {
+        let f = |x: Dual<f64>| Float::powf(x, Dual::constant(3.0));
+        let y = f(Dual::variable(2.0, 0));
+        assert_eq!(y.value(), 8.0);
+        assert_eq!(y.derivative(), [12.0]);
+    }
+
+    #[test]
+    fn powf_both_variable() {
+        // f(x, y) = x^y: d/dx = y*x^(y-1), d/dy = x^y*ln(x)
+        let x = Dual::<f64, 2>::variable(2.0, 0);
+        let y = Dual::<f64, 2>::variable(3.0, 1);
+        let z = Float::powf(x, y);
+        assert_eq!(z.value(), 8.0);
+        assert_eq!(z.derivative(), [12.0, 8.0 * 2.0f64.ln()]);
+    }
+
+    #[test]
+    fn recip() {
+        let f = |x: Dual<f64>| Float::recip(x);
+        let y = f(Dual::variable(2.0, 0));
+        assert_eq!(y.value(), 0.5);
+        assert_eq!(y.derivative(), [-0.25]);
+    }
+
+    #[test]
+    fn hypot() {
+        let x = Dual::<f64, 2>::variable(3.0, 0);
+        let y = Dual::<f64, 2>::variable(4.0, 1);
+        let h = Float::hypot(x, y);
+        assert_eq!(h.value(), 5.0);
+        assert_eq!(h.derivative(), [0.6, 0.8]);
+    }
+
+    #[test]
+    fn floor_ceil_round_trunc() {
+        let x = Dual::<f64>::variable(2.7, 0);
+        assert_eq!(Float::floor(x).value(), 2.0);
+        assert_eq!(Float::floor(x).derivative(), [0.0]);
+        assert_eq!(Float::ceil(x).value(), 3.0);
+        assert_eq!(Float::ceil(x).derivative(), [0.0]);
+        assert_eq!(Float::round(x).value(), 3.0);
+        assert_eq!(Float::round(x).derivative(), [0.0]);
+        assert_eq!(Float::trunc(x).value(), 2.0);
+        assert_eq!(Float::trunc(x).derivative(), [0.0]);
+    }
+
+    #[test]
+    fn fract() {
+        let x = Dual::<f64>::variable(2.7, 0);
+        let y = Float::fract(x);
+        assert!((y.value() - 0.7).abs() < 1e-10);
+        assert_eq!(y.derivative(), [1.0]);
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Dual::<f64>::variable(2.0, 0);
+        let b = Dual::<f64>::constant(5.0);
+        assert_eq!(Float::min(a, b).value(), 2.0);
+        assert_eq!(Float::max(a, b).value(), 5.0);
+    }
+
+    #[test]
+    fn sin_cos() {
+        let x = Dual::<f64>::variable(1.0, 0);
+        let (s, c) = Float::sin_cos(x);
+        assert_eq!(s.value(), 1.0f64.sin());
+        assert_eq!(c.value(), 1.0f64.cos());
+    }
+
+    #[test]
+    fn exp_m1_ln_1p() {
+        let x = Dual::<f64>::variable(1.0, 0);
+        let y = Float::exp_m1(x);
+        assert_eq!(y.value(), 1.0f64.exp_m1());
+        assert_eq!(y.derivative(), [1.0f64.exp()]);
+
+        let z = Float::ln_1p(x);
+        assert_eq!(z.value(), 1.0f64.ln_1p());
+        assert_eq!(z.derivative(), [0.5]);
+    }
+
+    #[test]
+    fn hyperbolic() {
+        let x = Dual::<f64>::variable(1.0, 0);
+        assert_eq!(Float::sinh(x).value(), 1.0f64.sinh());
+        assert_eq!(Float::sinh(x).derivative(), [1.0f64.cosh()]);
+        assert_eq!(Float::cosh(x).value(), 1.0f64.cosh());
+        assert_eq!(Float::cosh(x).derivative(), [1.0f64.sinh()]);
+
+        let tanh = 1.0f64.tanh();
+        assert_eq!(Float::tanh(x).value(), tanh);
+        assert_eq!(Float::tanh(x).derivative(), [1.0 - tanh * tanh]);
+
+        assert_eq!(Float::asinh(x).value(), 1.0f64.asinh());
+        let half = Dual::<f64>::variable(0.5, 0);
+        assert_eq!(Float::atanh(half).value(), 0.5f64.atanh());
+
+        let two = Dual::<f64>::variable(2.0, 0);
+        assert_eq!(Float::acosh(two).value(), 2.0f64.acosh());
+    }
+
+    #[test]
+    fn degrees_radians() {
+        let x = Dual::<f64>::variable(std::f64::consts::PI, 0);
+        let deg = Float::to_degrees(x);
+        assert!((deg.value() - 180.0).abs() < 1e-10);
+
+        let y = Dual::<f64>::variable(180.0, 0);
+        let rad = Float::to_radians(y);
+        assert!((rad.value() - std::f64::consts::PI).abs() < 1e-10);
+    }
+
+    #[test]
+    fn classification() {
+        let finite = Dual::<f64>::variable(1.0, 0);
+        assert!(Float::is_finite(finite));
+        assert!(!Float::is_nan(finite));
+        assert!(!Float::is_infinite(finite));
+        assert!(Float::is_normal(finite));
+        assert_eq!(Float::classify(finite), std::num::FpCategory::Normal);
+
+        let nan: Dual<f64> = Float::nan();
+        assert!(Float::is_nan(nan));
+    }
+}
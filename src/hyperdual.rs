@@ -0,0 +1,294 @@
+//! Second-order forward-mode autodiff via hyperdual numbers.
+//!
+//! A [`HyperDual`] carries two independent first-order infinitesimal parts
+//! (`dx1`, `dx2`) and one second-order part (`dxdx`). Seeding `dx1 = dx2 = 1`
+//! and `dxdx = 0` then evaluating `f` yields `f(x)`, `f'(x)`, and `f''(x)`
+//! simultaneously from a single pass — useful for Newton's method and other
+//! curvature-aware optimization that first-order [`Dual`](crate::Dual)
+//! cannot provide.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct HyperDual<F: Float> {
+    x: F,
+    dx1: F,
+    dx2: F,
+    dxdx: F,
+}
+
+impl<F: Float> HyperDual<F> {
+    /// Seeds the single independent variable: `dx1 = dx2 = 1`, `dxdx = 0`.
+    #[inline]
+    pub fn variable(val: F) -> Self {
+        Self {
+            x: val,
+            dx1: F::one(),
+            dx2: F::one(),
+            dxdx: F::zero(),
+        }
+    }
+
+    /// Wraps a value with no dependence on the input variable.
+    #[inline]
+    pub fn constant(val: F) -> Self {
+        Self {
+            x: val,
+            dx1: F::zero(),
+            dx2: F::zero(),
+            dxdx: F::zero(),
+        }
+    }
+
+    /// Applies a scalar function given its value, first, and second
+    /// derivative at `self.x`, propagating both derivatives through the
+    /// chain rule (and, for `dxdx`, the product rule across `dx1`/`dx2`).
+    #[inline]
+    fn chain(self, fx: F, dfx: F, ddfx: F) -> Self {
+        Self {
+            x: fx,
+            dx1: dfx * self.dx1,
+            dx2: dfx * self.dx2,
+            dxdx: dfx * self.dxdx + ddfx * self.dx1 * self.dx2,
+        }
+    }
+
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        let root = self.x.sqrt();
+        let two = F::from(2.0).unwrap();
+        let four = F::from(4.0).unwrap();
+        self.chain(
+            root,
+            F::one() / (two * root),
+            -F::one() / (four * root * root * root),
+        )
+    }
+
+    #[inline]
+    pub fn exp(self) -> Self {
+        let exp = self.x.exp();
+        self.chain(exp, exp, exp)
+    }
+
+    #[inline]
+    pub fn ln(self) -> Self {
+        self.chain(
+            self.x.ln(),
+            F::one() / self.x,
+            -F::one() / (self.x * self.x),
+        )
+    }
+
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.chain(self.x.sin(), self.x.cos(), -self.x.sin())
+    }
+
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.chain(self.x.cos(), -self.x.sin(), -self.x.cos())
+    }
+
+    #[inline]
+    pub fn tan(self) -> Self {
+        let tan = self.x.tan();
+        let sec2 = F::one() + tan * tan;
+        self.chain(tan, sec2, F::from(2.0).unwrap() * tan * sec2)
+    }
+
+    pub fn value(&self) -> F {
+        self.x
+    }
+
+    /// The first derivative `f'(x)`.
+    pub fn first_derivative(&self) -> F {
+        self.dx1
+    }
+
+    /// The second derivative `f''(x)`.
+    pub fn second_derivative(&self) -> F {
+        self.dxdx
+    }
+}
+
+impl<F: Float> Neg for HyperDual<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: self.x.neg(),
+            dx1: self.dx1.neg(),
+            dx2: self.dx2.neg(),
+            dxdx: self.dxdx.neg(),
+        }
+    }
+}
+
+// Sum rule
+impl<F: Float> Add for HyperDual<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            dx1: self.dx1 + rhs.dx1,
+            dx2: self.dx2 + rhs.dx2,
+            dxdx: self.dxdx + rhs.dxdx,
+        }
+    }
+}
+
+// Difference rule
+impl<F: Float> Sub for HyperDual<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            dx1: self.dx1 - rhs.dx1,
+            dx2: self.dx2 - rhs.dx2,
+            dxdx: self.dxdx - rhs.dxdx,
+        }
+    }
+}
+
+// Product rule
+impl<F: Float> Mul for HyperDual<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            dx1: self.x * rhs.dx1 + self.dx1 * rhs.x,
+            dx2: self.x * rhs.dx2 + self.dx2 * rhs.x,
+            dxdx: self.x * rhs.dxdx + self.dx1 * rhs.dx2 + self.dx2 * rhs.dx1 + self.dxdx * rhs.x,
+        }
+    }
+}
+
+// Quotient rule: a / b == a * (1 / b), and 1/b is just the chain rule
+// applied to x -> 1/x.
+impl<F: Float> Div for HyperDual<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let recip_x = F::one() / rhs.x;
+        let recip = rhs.chain(
+            recip_x,
+            -recip_x * recip_x,
+            F::from(2.0).unwrap() * recip_x * recip_x * recip_x,
+        );
+        self * recip
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_second_derivative() {
+        // f(x) = x^2 via x * x: f(2) = 4, f'(2) = 4, f''(2) = 2
+        let x = HyperDual::variable(2.0);
+        let y = x * x;
+        assert_eq!(y.value(), 4.0);
+        assert_eq!(y.first_derivative(), 4.0);
+        assert_eq!(y.second_derivative(), 2.0);
+    }
+
+    #[test]
+    fn sin_second_derivative() {
+        // f(x) = sin(x): f''(x) = -sin(x)
+        let x = HyperDual::variable(1.0);
+        let y = x.sin();
+        assert_eq!(y.value(), 1.0f64.sin());
+        assert_eq!(y.first_derivative(), 1.0f64.cos());
+        assert_eq!(y.second_derivative(), -1.0f64.sin());
+    }
+
+    #[test]
+    fn cos_second_derivative() {
+        // f(x) = cos(x): f''(x) = -cos(x)
+        let x = HyperDual::variable(1.0);
+        let y = x.cos();
+        assert_eq!(y.value(), 1.0f64.cos());
+        assert_eq!(y.first_derivative(), -1.0f64.sin());
+        assert_eq!(y.second_derivative(), -1.0f64.cos());
+    }
+
+    #[test]
+    fn tan_second_derivative() {
+        // f(x) = tan(x): f'(x) = sec^2(x), f''(x) = 2*tan(x)*sec^2(x)
+        let x = HyperDual::variable(1.0);
+        let y = x.tan();
+        let tan = 1.0f64.tan();
+        let sec2 = 1.0 + tan * tan;
+        assert_eq!(y.value(), tan);
+        assert_eq!(y.first_derivative(), sec2);
+        assert_eq!(y.second_derivative(), 2.0 * tan * sec2);
+    }
+
+    #[test]
+    fn ln_second_derivative() {
+        // f(x) = ln(x): f'(x) = 1/x, f''(x) = -1/x^2
+        let x = HyperDual::variable(2.0);
+        let y = x.ln();
+        assert_eq!(y.value(), 2.0f64.ln());
+        assert_eq!(y.first_derivative(), 0.5);
+        assert_eq!(y.second_derivative(), -0.25);
+    }
+
+    #[test]
+    fn exp_second_derivative() {
+        // f(x) = exp(x): f'(x) = f''(x) = exp(x)
+        let x = HyperDual::variable(1.0);
+        let y = x.exp();
+        let exp = 1.0f64.exp();
+        assert_eq!(y.value(), exp);
+        assert_eq!(y.first_derivative(), exp);
+        assert_eq!(y.second_derivative(), exp);
+    }
+
+    #[test]
+    fn neg() {
+        let x = HyperDual::variable(2.0);
+        let y = -x;
+        assert_eq!(y.value(), -2.0);
+        assert_eq!(y.first_derivative(), -1.0);
+        assert_eq!(y.second_derivative(), 0.0);
+    }
+
+    #[test]
+    fn add() {
+        // f(x) = x + x^2: f'(x) = 1 + 2x, f''(x) = 2
+        let x = HyperDual::variable(2.0);
+        let y = x + x * x;
+        assert_eq!(y.value(), 6.0);
+        assert_eq!(y.first_derivative(), 5.0);
+        assert_eq!(y.second_derivative(), 2.0);
+    }
+
+    #[test]
+    fn sub() {
+        // f(x) = x^2 - x: f'(x) = 2x - 1, f''(x) = 2
+        let x = HyperDual::variable(2.0);
+        let y = x * x - x;
+        assert_eq!(y.value(), 2.0);
+        assert_eq!(y.first_derivative(), 3.0);
+        assert_eq!(y.second_derivative(), 2.0);
+    }
+
+    #[test]
+    fn div_second_derivative() {
+        // f(x) = 1/x: f'(x) = -1/x^2, f''(x) = 2/x^3
+        let one = HyperDual::constant(1.0);
+        let x = HyperDual::variable(2.0);
+        let y = one / x;
+        assert_eq!(y.value(), 0.5);
+        assert_eq!(y.first_derivative(), -0.25);
+        assert_eq!(y.second_derivative(), 0.25);
+    }
+}
@@ -1,22 +1,44 @@
 use std::{
     fmt::Debug,
-    ops::{Add, Div, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use num_traits::{Float, Pow};
 
+pub mod hyperdual;
+pub mod reverse;
+
+mod float;
+pub use float::differentiate;
+
+/// A forward-mode dual number.
+///
+/// `x` is the scalar value of the function being evaluated, and `dx` holds
+/// one partial derivative per independent input variable, so `Dual<F, N>`
+/// tracks the full gradient of a scalar function of `N` variables in a
+/// single pass. Most uses only need one input variable, so `N` defaults to
+/// `1`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-pub struct Dual<F: Float, const N: usize> {
-    x: [F; N],
+pub struct Dual<F: Float, const N: usize = 1> {
+    x: F,
     dx: [F; N],
 }
 
 impl<F: Float, const N: usize> Dual<F, N> {
+    /// Seeds an independent variable: `dx[index]` is `1`, every other slot is `0`.
     #[inline]
-    pub fn new(val: F) -> Self {
+    pub fn variable(val: F, index: usize) -> Self {
+        let mut dx = [F::zero(); N];
+        dx[index] = F::one();
+        Self { x: val, dx }
+    }
+
+    /// Wraps a value that does not depend on any input variable (`dx` all zero).
+    #[inline]
+    pub fn constant(val: F) -> Self {
         Self {
-            x: [val; N],
-            dx: [F::one(); N],
+            x: val,
+            dx: [F::zero(); N],
         }
     }
 
@@ -27,18 +49,18 @@ impl<F: Float, const N: usize> Dual<F, N> {
 
     #[inline]
     pub fn exp(self) -> Self {
-        for i in 0..N {
-            self.x[i] = self.x[i].exp();
-            self.dx[i] = self.x[i].exp() * self.dx[i];
+        let exp = self.x.exp();
+        Dual {
+            x: exp,
+            dx: self.dx.map(|d| exp * d),
         }
-        self
     }
 
     #[inline]
     pub fn ln(self) -> Self {
         Dual {
             x: self.x.ln(),
-            dx: self.x.powi(-1) * self.dx,
+            dx: self.dx.map(|d| d / self.x),
         }
     }
 
@@ -46,7 +68,7 @@ impl<F: Float, const N: usize> Dual<F, N> {
     pub fn log(self, base: F) -> Self {
         Dual {
             x: self.x.log(base),
-            dx: (base.ln() * self.x).powi(-1) * self.dx,
+            dx: self.dx.map(|d| d / (self.x * base.ln())),
         }
     }
 
@@ -54,7 +76,7 @@ impl<F: Float, const N: usize> Dual<F, N> {
     pub fn sin(self) -> Self {
         Dual {
             x: self.x.sin(),
-            dx: self.x.cos() * self.dx,
+            dx: self.dx.map(|d| self.x.cos() * d),
         }
     }
 
@@ -62,7 +84,7 @@ impl<F: Float, const N: usize> Dual<F, N> {
     pub fn cos(self) -> Self {
         Dual {
             x: self.x.cos(),
-            dx: -self.x.sin() * self.dx,
+            dx: self.dx.map(|d| -self.x.sin() * d),
         }
     }
 
@@ -70,31 +92,121 @@ impl<F: Float, const N: usize> Dual<F, N> {
     pub fn tan(self) -> Self {
         Dual {
             x: self.x.tan(),
-            dx: self.x.cos().powi(-2) * self.dx,
+            dx: self.dx.map(|d| d / self.x.cos().powi(2)),
         }
     }
 
     #[inline]
     pub fn asin(self) -> Self {
+        let denom = (F::one() - self.x.powi(2)).sqrt();
         Dual {
             x: self.x.asin(),
-            dx: (F::one() - self.x.powi(2)).sqrt().powi(-1) * self.dx,
+            dx: self.dx.map(|d| d / denom),
         }
     }
 
     #[inline]
     pub fn acos(self) -> Self {
+        let denom = (F::one() - self.x.powi(2)).sqrt();
         Dual {
             x: self.x.acos(),
-            dx: -(F::one() - self.x.powi(2)).sqrt().powi(-1) * self.dx,
+            dx: self.dx.map(|d| -d / denom),
         }
     }
 
     #[inline]
     pub fn atan(self) -> Self {
+        let denom = F::one() + self.x.powi(2);
         Dual {
             x: self.x.atan(),
-            dx: (F::one() + self.x.powi(2)).powi(-1) * self.dx,
+            dx: self.dx.map(|d| d / denom),
+        }
+    }
+
+    /// `|x|`, derivative `sign(x) * dx`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Dual {
+            x: self.x.abs(),
+            dx: self.dx.map(|d| d * self.x.signum()),
+        }
+    }
+
+    /// `self * a + b`, with the product rule applied to `self * a` before adding `b`.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Dual {
+            x: self.x.mul_add(a.x, b.x),
+            dx: std::array::from_fn(|i| self.x * a.dx[i] + a.x * self.dx[i] + b.dx[i]),
+        }
+    }
+
+    #[inline]
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            // x^0 is the constant function 1, regardless of x (even x == 0).
+            return Dual::constant(F::one());
+        }
+        let coeff = F::from(n).unwrap() * self.x.powi(n - 1);
+        Dual {
+            x: self.x.powi(n),
+            dx: self.dx.map(|d| coeff * d),
+        }
+    }
+
+    #[inline]
+    pub fn cbrt(self) -> Self {
+        let root = self.x.cbrt();
+        let denom = F::from(3.0).unwrap() * root * root;
+        Dual {
+            x: root,
+            dx: self.dx.map(|d| d / denom),
+        }
+    }
+
+    #[inline]
+    pub fn exp2(self) -> Self {
+        let exp2 = self.x.exp2();
+        let ln2 = F::from(2.0).unwrap().ln();
+        Dual {
+            x: exp2,
+            dx: self.dx.map(|d| ln2 * exp2 * d),
+        }
+    }
+
+    #[inline]
+    pub fn log2(self) -> Self {
+        let ln2 = F::from(2.0).unwrap().ln();
+        Dual {
+            x: self.x.log2(),
+            dx: self.dx.map(|d| d / (self.x * ln2)),
+        }
+    }
+
+    #[inline]
+    pub fn log10(self) -> Self {
+        let ln10 = F::from(10.0).unwrap().ln();
+        Dual {
+            x: self.x.log10(),
+            dx: self.dx.map(|d| d / (self.x * ln10)),
+        }
+    }
+
+    #[inline]
+    pub fn hypot(self, other: Self) -> Self {
+        let h = self.x.hypot(other.x);
+        Dual {
+            x: h,
+            dx: std::array::from_fn(|i| (self.x * self.dx[i] + other.x * other.dx[i]) / h),
+        }
+    }
+
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        let r2 = self.x * self.x + other.x * other.x;
+        Dual {
+            x: self.x.atan2(other.x),
+            dx: std::array::from_fn(|i| (other.x * self.dx[i] - self.x * other.dx[i]) / r2),
         }
     }
 
@@ -102,18 +214,31 @@ impl<F: Float, const N: usize> Dual<F, N> {
         self.x
     }
 
-    pub fn derivative(&self) -> F {
+    /// The full gradient: one partial derivative per input variable.
+    pub fn derivative(&self) -> [F; N] {
+        self.dx
+    }
+
+    /// Alias for [`Dual::derivative`].
+    pub fn grad(&self) -> [F; N] {
         self.dx
     }
 }
 
+impl<F: Float> Dual<F, 1> {
+    #[inline]
+    pub fn new(val: F) -> Self {
+        Self::variable(val, 0)
+    }
+}
+
 impl<F: Float, const N: usize> Neg for Dual<F, N> {
     type Output = Dual<F, N>;
 
     fn neg(self) -> Self::Output {
         Dual {
             x: self.x.neg(),
-            dx: self.dx.neg(),
+            dx: self.dx.map(|d| d.neg()),
         }
     }
 }
@@ -125,7 +250,7 @@ impl<F: Float, const N: usize> Add for Dual<F, N> {
     fn add(self, rhs: Self) -> Self::Output {
         Dual {
             x: self.x + rhs.x,
-            dx: self.dx + rhs.dx,
+            dx: std::array::from_fn(|i| self.dx[i] + rhs.dx[i]),
         }
     }
 }
@@ -185,19 +310,32 @@ impl<F: Float, const N: usize> Sub for Dual<F, N> {
     fn sub(self, rhs: Self) -> Self::Output {
         Dual {
             x: self.x - rhs.x,
-            dx: self.dx - rhs.dx,
+            dx: std::array::from_fn(|i| self.dx[i] - rhs.dx[i]),
+        }
+    }
+}
+
+// Difference constant
+impl<F: Float, const N: usize> Sub<F> for Dual<F, N> {
+    type Output = Dual<F, N>;
+
+    fn sub(self, rhs: F) -> Self::Output {
+        Dual {
+            x: self.x - rhs,
+            dx: self.dx,
         }
     }
 }
 
 // Product rule
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl<F: Float, const N: usize> Mul for Dual<F, N> {
     type Output = Dual<F, N>;
 
     fn mul(self, rhs: Dual<F, N>) -> Self::Output {
         Dual {
             x: self.x * rhs.x,
-            dx: self.x * rhs.dx + rhs.x * self.dx,
+            dx: std::array::from_fn(|i| self.x * rhs.dx[i] + rhs.x * self.dx[i]),
         }
     }
 }
@@ -209,7 +347,7 @@ impl<F: Float, const N: usize> Mul<F> for Dual<F, N> {
     fn mul(self, rhs: F) -> Self::Output {
         Dual {
             x: self.x * rhs,
-            dx: self.dx * rhs,
+            dx: self.dx.map(|d| d * rhs),
         }
     }
 }
@@ -221,7 +359,7 @@ impl<const N: usize> Mul<Dual<f32, N>> for f32 {
     fn mul(self, rhs: Dual<f32, N>) -> Self::Output {
         Dual {
             x: self * rhs.x,
-            dx: self * rhs.dx,
+            dx: rhs.dx.map(|d| self * d),
         }
     }
 }
@@ -233,23 +371,86 @@ impl<const N: usize> Mul<Dual<f64, N>> for f64 {
     fn mul(self, rhs: Dual<f64, N>) -> Self::Output {
         Dual {
             x: self * rhs.x,
-            dx: self * rhs.dx,
+            dx: rhs.dx.map(|d| self * d),
         }
     }
 }
 
 // Quotient rule
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl<F: Float, const N: usize> Div for Dual<F, N> {
     type Output = Dual<F, N>;
 
     fn div(self, rhs: Dual<F, N>) -> Self::Output {
         Dual {
             x: self.x / rhs.x,
-            dx: (self.x * rhs.dx + rhs.x * self.dx) / (rhs.x * rhs.x),
+            dx: std::array::from_fn(|i| {
+                (self.dx[i] * rhs.x - self.x * rhs.dx[i]) / (rhs.x * rhs.x)
+            }),
         }
     }
 }
 
+// Quotient constant
+impl<F: Float, const N: usize> Div<F> for Dual<F, N> {
+    type Output = Dual<F, N>;
+
+    fn div(self, rhs: F) -> Self::Output {
+        Dual {
+            x: self.x / rhs,
+            dx: self.dx.map(|d| d / rhs),
+        }
+    }
+}
+
+impl<F: Float, const N: usize> AddAssign for Dual<F, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Float, const N: usize> AddAssign<F> for Dual<F, N> {
+    fn add_assign(&mut self, rhs: F) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Float, const N: usize> SubAssign for Dual<F, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Float, const N: usize> SubAssign<F> for Dual<F, N> {
+    fn sub_assign(&mut self, rhs: F) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Float, const N: usize> MulAssign for Dual<F, N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Float, const N: usize> MulAssign<F> for Dual<F, N> {
+    fn mul_assign(&mut self, rhs: F) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Float, const N: usize> DivAssign for Dual<F, N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<F: Float, const N: usize> DivAssign<F> for Dual<F, N> {
+    fn div_assign(&mut self, rhs: F) {
+        *self = *self / rhs;
+    }
+}
+
 // Power rule
 impl<F: Float, const N: usize> Pow<F> for Dual<F, N> {
     type Output = Dual<F, N>;
@@ -257,7 +458,7 @@ impl<F: Float, const N: usize> Pow<F> for Dual<F, N> {
     fn pow(self, rhs: F) -> Self::Output {
         Dual {
             x: self.x.powf(rhs),
-            dx: rhs * self.x.powf(rhs - F::one()) * self.dx, // n * x^(n-1) * d/dx
+            dx: self.dx.map(|d| rhs * self.x.powf(rhs - F::one()) * d), // n * x^(n-1) * d/dx
         }
     }
 }
@@ -267,9 +468,10 @@ impl<F: Float, const N: usize> Pow<Dual<F, N>> for (F,) {
     type Output = Dual<F, N>;
 
     fn pow(self, rhs: Dual<F, N>) -> Self::Output {
+        let x = self.0.powf(rhs.x);
         Dual {
-            x: self.0.powf(rhs.x),
-            dx: self.0.ln() * self.0.powf(rhs.x) * rhs.dx,
+            x,
+            dx: rhs.dx.map(|d| self.0.ln() * x * d),
         }
     }
 }
@@ -279,9 +481,10 @@ impl<const N: usize> Pow<Dual<f32, N>> for f32 {
     type Output = Dual<f32, N>;
 
     fn pow(self, rhs: Dual<f32, N>) -> Self::Output {
+        let x = self.powf(rhs.x);
         Dual {
-            x: self.powf(rhs.x),
-            dx: self.ln() * self.powf(rhs.x) * rhs.dx,
+            x,
+            dx: rhs.dx.map(|d| self.ln() * x * d),
         }
     }
 }
@@ -291,9 +494,10 @@ impl<const N: usize> Pow<Dual<f64, N>> for f64 {
     type Output = Dual<f64, N>;
 
     fn pow(self, rhs: Dual<f64, N>) -> Self::Output {
+        let x = self.powf(rhs.x);
         Dual {
-            x: self.powf(rhs.x),
-            dx: self.ln() * self.powf(rhs.x) * rhs.dx,
+            x,
+            dx: rhs.dx.map(|d| self.ln() * x * d),
         }
     }
 }
@@ -305,17 +509,17 @@ macro_rules! dual {
     }};
 }
 
-impl<const N: usize> From<[f32; N]> for Dual<f32, N> {
+impl<const N: usize> From<f32> for Dual<f32, N> {
     #[inline]
     fn from(input: f32) -> Self {
-        Dual::new(input)
+        Dual::constant(input)
     }
 }
 
-impl<const N: usize> From<[f64; N]> for Dual<f64, N> {
+impl<const N: usize> From<f64> for Dual<f64, N> {
     #[inline]
     fn from(input: f64) -> Self {
-        Dual::new(input)
+        Dual::constant(input)
     }
 }
 
@@ -328,7 +532,7 @@ mod tests {
         let x = dual!(3.0);
         let y = x * x + 2.0;
         assert_eq!(y.x, 11.0);
-        assert_eq!(y.dx, 6.0);
+        assert_eq!(y.dx, [6.0]);
     }
 
     #[test]
@@ -336,7 +540,7 @@ mod tests {
         let sin = |x: Dual<_>| x.sin();
         let y_1 = sin(dual!(1.0));
         assert_eq!(y_1.x, 0.8414709848078965);
-        assert_eq!(y_1.dx, 0.5403023058681398);
+        assert_eq!(y_1.dx, [0.5403023058681398]);
     }
 
     #[test]
@@ -344,7 +548,7 @@ mod tests {
         let cos = |x: Dual<_>| x.cos();
         let y_1 = cos(dual!(1.0));
         assert_eq!(y_1.x, 0.5403023058681398);
-        assert_eq!(y_1.dx, -0.8414709848078965);
+        assert_eq!(y_1.dx, [-0.8414709848078965]);
     }
 
     #[test]
@@ -352,23 +556,23 @@ mod tests {
         let tan = |x: Dual<_>| x.tan();
         let y_1 = tan(dual!(1.0));
         assert_eq!(y_1.x, 1.5574077246549023);
-        assert_eq!(y_1.dx, 3.425518820814759);
+        assert_eq!(y_1.dx, [3.425518820814759]);
     }
 
     #[test]
     fn asin() {
         let asin = |x: Dual<_>| x.asin();
         let y_05 = asin(dual!(0.5));
-        assert_eq!(y_05.x, 0.5235987755982989);
-        assert_eq!(y_05.dx, 1.1547005383792517);
+        assert!((y_05.x - std::f64::consts::FRAC_PI_6).abs() < 1e-12);
+        assert_eq!(y_05.dx, [1.1547005383792517]);
     }
 
     #[test]
     fn acos() {
         let acos = |x: Dual<_>| x.acos();
         let y_05 = acos(dual!(0.5));
-        assert_eq!(y_05.x, 1.0471975511965979);
-        assert_eq!(y_05.dx, -1.1547005383792517);
+        assert!((y_05.x - std::f64::consts::FRAC_PI_3).abs() < 1e-12);
+        assert_eq!(y_05.dx, [-1.1547005383792517]);
     }
 
     #[test]
@@ -376,7 +580,7 @@ mod tests {
         let atan = |x: Dual<_>| x.atan();
         let y_05 = atan(dual!(0.5));
         assert_eq!(y_05.x, 0.4636476090008061);
-        assert_eq!(y_05.dx, 0.8);
+        assert_eq!(y_05.dx, [0.8]);
     }
 
     #[test]
@@ -384,7 +588,7 @@ mod tests {
         let sqrt = |x: Dual<_>| x.sqrt();
         let y_1 = sqrt(dual!(1.0));
         assert_eq!(y_1.x, 1.0);
-        assert_eq!(y_1.dx, 0.5);
+        assert_eq!(y_1.dx, [0.5]);
     }
 
     #[test]
@@ -392,15 +596,15 @@ mod tests {
         let exp = |x: Dual<_>| x.exp();
         let y_1 = exp(dual!(1.0));
         assert_eq!(y_1.x, std::f32::consts::E);
-        assert_eq!(y_1.dx, std::f32::consts::E);
+        assert_eq!(y_1.dx, [std::f32::consts::E]);
     }
 
     #[test]
     fn ln() {
         let ln = |x: Dual<_>| x.ln();
         let y_2 = ln(dual!(2.0));
-        assert_eq!(y_2.x, 0.6931471805599453);
-        assert_eq!(y_2.dx, 0.5);
+        assert!((y_2.x - std::f64::consts::LN_2).abs() < 1e-12);
+        assert_eq!(y_2.dx, [0.5]);
     }
 
     #[test]
@@ -408,7 +612,7 @@ mod tests {
         let log = |x: Dual<_>| x.log(10.0);
         let y_2 = log(dual!(2.0));
         assert_eq!(y_2.x, 0.30102999566398114);
-        assert_eq!(y_2.dx, 0.21714724095162588);
+        assert_eq!(y_2.dx, [0.21714724095162588]);
     }
 
     #[test]
@@ -416,7 +620,7 @@ mod tests {
         let f = |x: Dual<f32>| 1.0 + x * 3.0;
         let y_2 = f(dual!(2.0));
         assert_eq!(y_2.x, 7.0);
-        assert_eq!(y_2.dx, 3.0);
+        assert_eq!(y_2.dx, [3.0]);
     }
 
     #[test]
@@ -424,6 +628,116 @@ mod tests {
         let f = |x: Dual<f32>| x.sin() * x.cos();
         let y_1 = f(dual!(1.0));
         assert_eq!(y_1.x, 0.45464867);
-        assert_eq!(y_1.dx, -0.4161468);
+        assert_eq!(y_1.dx, [-0.4161468]);
+    }
+
+    #[test]
+    fn multivariate_gradient() {
+        // f(x, y) = x * y + x, df/dx = y + 1, df/dy = x
+        let f = |x: Dual<f64, 2>, y: Dual<f64, 2>| x * y + x;
+        let x = Dual::variable(3.0, 0);
+        let y = Dual::variable(4.0, 1);
+        let z = f(x, y);
+        assert_eq!(z.value(), 15.0);
+        assert_eq!(z.grad(), [5.0, 3.0]);
+    }
+
+    #[test]
+    fn abs() {
+        let f = |x: Dual<f64>| x.abs();
+        let y = f(Dual::variable(-3.0, 0));
+        assert_eq!(y.value(), 3.0);
+        assert_eq!(y.derivative(), [-1.0]);
+    }
+
+    #[test]
+    fn mul_add() {
+        // f(x) = x * 2 + 1, df/dx = 2
+        let f = |x: Dual<f64>| x.mul_add(Dual::constant(2.0), Dual::constant(1.0));
+        let y = f(Dual::variable(3.0, 0));
+        assert_eq!(y.value(), 7.0);
+        assert_eq!(y.derivative(), [2.0]);
+    }
+
+    #[test]
+    fn powi() {
+        let f = |x: Dual<f64>| x.powi(3);
+        let y = f(Dual::variable(2.0, 0));
+        assert_eq!(y.value(), 8.0);
+        assert_eq!(y.derivative(), [12.0]);
+    }
+
+    #[test]
+    fn powi_zero_exponent() {
+        // x^0 is the constant function 1, even at x == 0.
+        let y = Dual::variable(0.0, 0).powi(0);
+        assert_eq!(y.value(), 1.0);
+        assert_eq!(y.derivative(), [0.0]);
+    }
+
+    #[test]
+    fn cbrt() {
+        let f = |x: Dual<f64>| x.cbrt();
+        let y = f(Dual::variable(8.0, 0));
+        assert_eq!(y.value(), 2.0);
+        assert_eq!(y.derivative(), [1.0 / 12.0]);
+    }
+
+    #[test]
+    fn exp2() {
+        let f = |x: Dual<f64>| x.exp2();
+        let y = f(Dual::variable(3.0, 0));
+        assert_eq!(y.value(), 8.0);
+        assert_eq!(y.derivative(), [8.0 * 2.0f64.ln()]);
+    }
+
+    #[test]
+    fn log2() {
+        let f = |x: Dual<f64>| x.log2();
+        let y = f(Dual::variable(8.0, 0));
+        assert_eq!(y.value(), 3.0);
+        assert_eq!(y.derivative(), [1.0 / (8.0 * 2.0f64.ln())]);
+    }
+
+    #[test]
+    fn log10() {
+        let f = |x: Dual<f64>| x.log10();
+        let y = f(Dual::variable(100.0, 0));
+        assert_eq!(y.value(), 2.0);
+        assert_eq!(y.derivative(), [1.0 / (100.0 * 10.0f64.ln())]);
+    }
+
+    #[test]
+    fn hypot() {
+        let x = Dual::<f64, 2>::variable(3.0, 0);
+        let y = Dual::<f64, 2>::variable(4.0, 1);
+        let h = x.hypot(y);
+        assert_eq!(h.value(), 5.0);
+        assert_eq!(h.derivative(), [0.6, 0.8]);
+    }
+
+    #[test]
+    fn atan2() {
+        let y = Dual::<f64, 2>::variable(1.0, 0);
+        let x = Dual::<f64, 2>::variable(1.0, 1);
+        let z = y.atan2(x);
+        assert_eq!(z.value(), 1.0f64.atan2(1.0));
+        assert_eq!(z.derivative(), [0.5, -0.5]);
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut x = Dual::<f64, 1>::variable(3.0, 0);
+        x += Dual::constant(1.0);
+        x += 1.0;
+        x -= Dual::constant(2.0);
+        x -= 1.0;
+        x *= Dual::constant(2.0);
+        x *= 2.0;
+        x /= Dual::constant(2.0);
+        x /= 2.0;
+        // ((((((3 + 1) + 1) - 2) - 1) * 2) * 2) / 2 / 2 == 2
+        assert_eq!(x.value(), 2.0);
+        assert_eq!(x.derivative(), [1.0]);
     }
 }
@@ -0,0 +1,347 @@
+//! Reverse-mode (tape-based) automatic differentiation.
+//!
+//! Forward-mode [`Dual`](crate::Dual) pays one pass per input variable,
+//! which gets expensive for functions with many inputs and a single output
+//! (e.g. a loss function). Reverse mode instead records every operation on
+//! a [`Tape`] as a Wengert list and sweeps it backwards once to obtain the
+//! gradient with respect to every input, regardless of how many there are.
+
+use std::cell::RefCell;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::Float;
+
+/// A single entry in the Wengert list: the node's value, the indices of up
+/// to two parent nodes, and the local partial derivative of this node with
+/// respect to each parent.
+struct Node<F: Float> {
+    value: F,
+    parents: [Option<usize>; 2],
+    partials: [F; 2],
+}
+
+/// Owns the Wengert list that [`Variable`]s record their operations onto.
+///
+/// Call [`Tape::var`] to introduce leaf variables, combine them with the
+/// usual arithmetic operators and math functions, then call
+/// [`Variable::grad`] on the output to run the reverse sweep.
+///
+/// Combining two [`Variable`]s that were created from different `Tape`s is
+/// not checked: the result is pushed onto one tape while indexing into the
+/// other's node list, which can panic or silently read an unrelated node.
+/// Keep every `Variable` involved in a computation rooted in the same tape.
+#[derive(Default)]
+pub struct Tape<F: Float> {
+    nodes: RefCell<Vec<Node<F>>>,
+}
+
+impl<F: Float> Tape<F> {
+    /// Creates an empty tape.
+    pub fn new() -> Self {
+        Self {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Introduces a new leaf variable (no parents) with the given value.
+    pub fn var(&self, value: F) -> Variable<'_, F> {
+        let index = self.push(value, [None, None], [F::zero(), F::zero()]);
+        Variable { tape: self, index }
+    }
+
+    fn push(&self, value: F, parents: [Option<usize>; 2], partials: [F; 2]) -> usize {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node {
+            value,
+            parents,
+            partials,
+        });
+        nodes.len() - 1
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+}
+
+/// A node on a [`Tape`]: the value computed so far plus enough information
+/// to replay the chain rule backwards once the tape is swept.
+///
+/// All arithmetic between two `Variable`s assumes they share the same
+/// `Tape`; mixing `Variable`s from different tapes pushes onto one tape
+/// while indexing into the other's node list (panic or wrong result) and
+/// is not currently guarded against.
+#[derive(Clone, Copy)]
+pub struct Variable<'t, F: Float> {
+    tape: &'t Tape<F>,
+    index: usize,
+}
+
+impl<'t, F: Float> Variable<'t, F> {
+    /// The value this variable holds.
+    pub fn value(&self) -> F {
+        self.tape.nodes.borrow()[self.index].value
+    }
+
+    fn unary(self, value: F, partial: F) -> Self {
+        let index = self
+            .tape
+            .push(value, [Some(self.index), None], [partial, F::zero()]);
+        Variable {
+            tape: self.tape,
+            index,
+        }
+    }
+
+    fn binary(self, rhs: Self, value: F, partial_self: F, partial_rhs: F) -> Self {
+        let index = self.tape.push(
+            value,
+            [Some(self.index), Some(rhs.index)],
+            [partial_self, partial_rhs],
+        );
+        Variable {
+            tape: self.tape,
+            index,
+        }
+    }
+
+    pub fn sin(self) -> Self {
+        let x = self.value();
+        self.unary(x.sin(), x.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        let x = self.value();
+        self.unary(x.cos(), -x.sin())
+    }
+
+    pub fn tan(self) -> Self {
+        let x = self.value();
+        self.unary(x.tan(), F::one() / x.cos().powi(2))
+    }
+
+    pub fn exp(self) -> Self {
+        let exp = self.value().exp();
+        self.unary(exp, exp)
+    }
+
+    pub fn ln(self) -> Self {
+        let x = self.value();
+        self.unary(x.ln(), F::one() / x)
+    }
+
+    pub fn sqrt(self) -> Self {
+        let root = self.value().sqrt();
+        self.unary(root, F::one() / (F::from(2.0).unwrap() * root))
+    }
+
+    pub fn pow(self, n: F) -> Self {
+        let x = self.value();
+        self.unary(x.powf(n), n * x.powf(n - F::one()))
+    }
+
+    /// Runs the reverse sweep with this variable as the output and returns
+    /// the gradient with respect to every variable recorded on the tape.
+    pub fn grad(self) -> Grad<F> {
+        let mut adjoints = vec![F::zero(); self.tape.len()];
+        adjoints[self.index] = F::one();
+
+        let nodes = self.tape.nodes.borrow();
+        for i in (0..nodes.len()).rev() {
+            let adjoint = adjoints[i];
+            if adjoint == F::zero() {
+                continue;
+            }
+            let node = &nodes[i];
+            for (parent, partial) in node.parents.iter().zip(node.partials.iter()) {
+                if let Some(parent) = parent {
+                    adjoints[*parent] = adjoints[*parent] + adjoint * *partial;
+                }
+            }
+        }
+
+        Grad { adjoints }
+    }
+}
+
+impl<'t, F: Float> Add for Variable<'t, F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.binary(rhs, self.value() + rhs.value(), F::one(), F::one())
+    }
+}
+
+impl<'t, F: Float> Sub for Variable<'t, F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.binary(rhs, self.value() - rhs.value(), F::one(), -F::one())
+    }
+}
+
+impl<'t, F: Float> Mul for Variable<'t, F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (x, y) = (self.value(), rhs.value());
+        self.binary(rhs, x * y, y, x)
+    }
+}
+
+impl<'t, F: Float> Div for Variable<'t, F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (x, y) = (self.value(), rhs.value());
+        self.binary(rhs, x / y, F::one() / y, -x / (y * y))
+    }
+}
+
+impl<'t, F: Float> Neg for Variable<'t, F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let x = self.value();
+        self.unary(-x, -F::one())
+    }
+}
+
+/// The result of a reverse sweep: the adjoint (partial derivative) of the
+/// output with respect to every node on the tape, indexed by [`Variable`].
+pub struct Grad<F: Float> {
+    adjoints: Vec<F>,
+}
+
+impl<F: Float> Grad<F> {
+    /// The partial derivative of the output with respect to `var`.
+    pub fn wrt(&self, var: Variable<'_, F>) -> F {
+        self.adjoints[var.index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product() {
+        // z = x * y, dz/dx = y, dz/dy = x
+        let tape = Tape::new();
+        let x = tape.var(3.0);
+        let y = tape.var(4.0);
+        let z = x * y;
+        assert_eq!(z.value(), 12.0);
+
+        let grad = z.grad();
+        assert_eq!(grad.wrt(x), 4.0);
+        assert_eq!(grad.wrt(y), 3.0);
+    }
+
+    #[test]
+    fn many_inputs() {
+        // f(a, b, c) = a * b + c, one backward pass gives all three partials
+        let tape = Tape::new();
+        let a = tape.var(2.0);
+        let b = tape.var(5.0);
+        let c = tape.var(7.0);
+        let f = a * b + c;
+        assert_eq!(f.value(), 17.0);
+
+        let grad = f.grad();
+        assert_eq!(grad.wrt(a), 5.0);
+        assert_eq!(grad.wrt(b), 2.0);
+        assert_eq!(grad.wrt(c), 1.0);
+    }
+
+    #[test]
+    fn sin_cos() {
+        let tape = Tape::new();
+        let x = tape.var(1.0);
+        let y = x.sin();
+        assert_eq!(y.value(), 1.0f64.sin());
+        assert_eq!(y.grad().wrt(x), 1.0f64.cos());
+    }
+
+    #[test]
+    fn sub() {
+        // z = x - y, dz/dx = 1, dz/dy = -1
+        let tape = Tape::new();
+        let x = tape.var(5.0);
+        let y = tape.var(3.0);
+        let z = x - y;
+        assert_eq!(z.value(), 2.0);
+
+        let grad = z.grad();
+        assert_eq!(grad.wrt(x), 1.0);
+        assert_eq!(grad.wrt(y), -1.0);
+    }
+
+    #[test]
+    fn div() {
+        // z = x / y, dz/dx = 1/y, dz/dy = -x/y^2
+        let tape = Tape::new();
+        let x = tape.var(6.0);
+        let y = tape.var(3.0);
+        let z = x / y;
+        assert_eq!(z.value(), 2.0);
+
+        let grad = z.grad();
+        assert_eq!(grad.wrt(x), 1.0 / 3.0);
+        assert_eq!(grad.wrt(y), -6.0 / 9.0);
+    }
+
+    #[test]
+    fn neg() {
+        let tape = Tape::new();
+        let x = tape.var(4.0);
+        let y = -x;
+        assert_eq!(y.value(), -4.0);
+        assert_eq!(y.grad().wrt(x), -1.0);
+    }
+
+    #[test]
+    fn tan() {
+        let tape = Tape::new();
+        let x = tape.var(1.0);
+        let y = x.tan();
+        assert_eq!(y.value(), 1.0f64.tan());
+        assert_eq!(y.grad().wrt(x), 1.0 / 1.0f64.cos().powi(2));
+    }
+
+    #[test]
+    fn exp() {
+        let tape = Tape::new();
+        let x = tape.var(1.0);
+        let y = x.exp();
+        assert_eq!(y.value(), 1.0f64.exp());
+        assert_eq!(y.grad().wrt(x), 1.0f64.exp());
+    }
+
+    #[test]
+    fn ln() {
+        let tape = Tape::new();
+        let x = tape.var(2.0);
+        let y = x.ln();
+        assert_eq!(y.value(), 2.0f64.ln());
+        assert_eq!(y.grad().wrt(x), 0.5);
+    }
+
+    #[test]
+    fn sqrt() {
+        let tape = Tape::new();
+        let x = tape.var(4.0);
+        let y = x.sqrt();
+        assert_eq!(y.value(), 2.0);
+        assert_eq!(y.grad().wrt(x), 0.25);
+    }
+
+    #[test]
+    fn pow() {
+        let tape = Tape::new();
+        let x = tape.var(2.0);
+        let y = x.pow(3.0);
+        assert_eq!(y.value(), 8.0);
+        assert_eq!(y.grad().wrt(x), 12.0);
+    }
+}